@@ -0,0 +1,128 @@
+//! Bluestein's algorithm for transforms of arbitrary length.
+//!
+//! The transform of a length `n` that is not a power of two is expressed as
+//! a convolution, which is then evaluated with the power-of-two machinery in
+//! `complex`. See Bluestein's chirp z-transform for details.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use complex::mixed_radix;
+use {Operation, Plan};
+
+/// Precomputed state for evaluating a length-`n` transform via Bluestein's
+/// algorithm.
+#[derive(Clone, Debug)]
+pub(crate) struct Bluestein<T> {
+    n: usize,
+    m: usize,
+    stages: Vec<usize>,
+    chirp: Vec<Complex<T>>,
+    kernel: Vec<Complex<T>>,
+    forward: Vec<Complex<T>>,
+    backward: Vec<Complex<T>>,
+}
+
+impl<T> Bluestein<T> where T: Float {
+    pub fn new(operation: Operation, n: usize) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        let pi = T::acos(-one);
+        let sign = if let Operation::Forward = operation { -one } else { one };
+
+        let m = (2 * n - 1).next_power_of_two();
+
+        let mut chirp = Vec::with_capacity(n);
+        for k in 0..n {
+            let angle = sign * pi * T::from(k * k).unwrap() / T::from(n).unwrap();
+            chirp.push(Complex::new(angle.cos(), angle.sin()));
+        }
+
+        let mut kernel = vec![Complex::new(zero, zero); m];
+        kernel[0] = chirp[0].conj();
+        for k in 1..n {
+            let b = chirp[k].conj();
+            kernel[k] = b;
+            kernel[m - k] = b;
+        }
+
+        let forward_plan = Plan::new(Operation::Forward, m);
+        let backward_plan = Plan::new(Operation::Backward, m);
+        mixed_radix(&mut kernel, &forward_plan.stages, &forward_plan.factors, Operation::Forward);
+
+        Bluestein {
+            n: n,
+            m: m,
+            stages: forward_plan.stages,
+            chirp: chirp,
+            kernel: kernel,
+            forward: forward_plan.factors,
+            backward: backward_plan.factors,
+        }
+    }
+
+    /// Evaluate the length-`n` transform of `data` using the chirp
+    /// convolution, writing the result back into `data`.
+    pub fn transform(&self, data: &mut [Complex<T>], operation: Operation) {
+        let zero = T::zero();
+        let n = self.n;
+        let m = self.m;
+
+        let mut buffer = vec![Complex::new(zero, zero); m];
+        for i in 0..n {
+            buffer[i] = data[i] * self.chirp[i];
+        }
+
+        mixed_radix(&mut buffer, &self.stages, &self.forward, Operation::Forward);
+        for i in 0..m {
+            buffer[i] = buffer[i] * self.kernel[i];
+        }
+        mixed_radix(&mut buffer, &self.stages, &self.backward, Operation::Inverse);
+
+        for i in 0..n {
+            data[i] = buffer[i] * self.chirp[i];
+        }
+
+        if let Operation::Inverse = operation {
+            let one_over_n = T::one() / T::from(n).unwrap();
+            for x in data.iter_mut() {
+                *x = *x * one_over_n;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {c64, Operation, Plan, Transform};
+    use test_util::naive_dft;
+
+    #[test]
+    fn matches_naive_dft_for_prime_length() {
+        let n = 11;
+        let input: Vec<c64> = (0..n).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut data = input.clone();
+
+        let plan = Plan::new_any(Operation::Forward, n);
+        Transform::transform(&mut data[..], &plan);
+
+        let expected = naive_dft(&input, -1.0);
+        for (a, b) in data.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn round_trips_for_prime_length() {
+        let n = 11;
+        let original: Vec<c64> = (0..n).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut data = original.clone();
+
+        Transform::transform(&mut data[..], &Plan::new_any(Operation::Forward, n));
+        Transform::transform(&mut data[..], &Plan::new_any(Operation::Inverse, n));
+
+        for (a, b) in data.iter().zip(original.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Complex-to-complex transform.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use {Operation, Plan, Transform, TransformError};
+
+/// The largest radix `butterfly` can combine. `Plan::new` never produces a
+/// larger one; lengths with a larger prime factor go through Bluestein's
+/// algorithm instead.
+const MAX_RADIX: usize = 7;
+
+impl<T> Transform<T> for [Complex<T>] where T: Float {
+    /// Perform the transform.
+    ///
+    /// Panics if `plan` was created with `Plan::czt`; such a plan is only
+    /// usable with `chirp_transform`. See `try_transform` for a non-panicking
+    /// alternative.
+    fn transform(&mut self, plan: &Plan<T>) {
+        assert_eq!(self.len(), plan.n);
+        assert!(plan.czt.is_none(), "the plan was created with Plan::czt; use chirp_transform");
+
+        if let Some(ref bluestein) = plan.bluestein {
+            bluestein.transform(self, plan.operation);
+            return;
+        }
+
+        mixed_radix(self, &plan.stages, &plan.factors, plan.operation);
+    }
+
+    fn try_transform(&mut self, plan: &Plan<T>) -> Result<(), TransformError> {
+        if self.len() != plan.n {
+            return Err(TransformError::LengthMismatch);
+        }
+        if plan.czt.is_some() {
+            return Err(TransformError::CztOnly);
+        }
+        self.transform(plan);
+        Ok(())
+    }
+}
+
+/// Perform the mixed-radix transform described by `stages` and `factors` in
+/// place, applying the `1 / n` normalization for `Operation::Inverse`.
+///
+/// `stages` lists the radices applied, in the order in which they are
+/// combined, and `factors` holds the concatenated per-stage twiddle tables
+/// produced by `Plan::new`.
+pub fn mixed_radix<T>(data: &mut [Complex<T>],
+                      stages: &[usize],
+                      factors: &[Complex<T>],
+                      operation: Operation)
+    where T: Float
+{
+    mixed_radix_pass(data, stages, factors);
+
+    if let Operation::Inverse = operation {
+        let one_over_n = T::one() / T::from(data.len()).unwrap();
+        for x in data.iter_mut() {
+            *x = *x * one_over_n;
+        }
+    }
+}
+
+/// Perform the mixed-radix transform described by `stages` and `factors` in
+/// place, without the `1 / n` normalization `mixed_radix` applies for
+/// `Operation::Inverse`.
+///
+/// This is the building block `transform_nd` uses to apply the
+/// normalization once, over the total element count, rather than once per
+/// axis.
+pub fn mixed_radix_pass<T>(data: &mut [Complex<T>], stages: &[usize], factors: &[Complex<T>])
+    where T: Float
+{
+    let n = data.len();
+
+    digit_reverse(data, stages);
+
+    let mut size = 1;
+    let mut offset = 0;
+    for &radix in stages {
+        let step = size * radix;
+        let table = &factors[offset..offset + step];
+        for block in (0..n).step_by(step) {
+            for j in 0..size {
+                butterfly(data, block, j, size, radix, step, table);
+            }
+        }
+        offset += step;
+        size = step;
+    }
+}
+
+/// Combine `radix` interleaved sub-transforms of length `size`, the `j`-th
+/// element of the group starting at `block`, into one sub-transform of
+/// length `step = size * radix`.
+fn butterfly<T>(data: &mut [Complex<T>],
+                block: usize,
+                j: usize,
+                size: usize,
+                radix: usize,
+                step: usize,
+                table: &[Complex<T>])
+    where T: Float
+{
+    let zero = Complex::new(T::zero(), T::zero());
+    let mut input = [zero; MAX_RADIX];
+    for p in 0..radix {
+        input[p] = data[block + j + p * size];
+    }
+    for q in 0..radix {
+        let mut sum = zero;
+        for p in 0..radix {
+            sum = sum + input[p] * table[(j + q * size) * p % step];
+        }
+        data[block + j + q * size] = sum;
+    }
+}
+
+/// Permute `data` into mixed-radix digit-reversed order, the generalization
+/// of bit-reversal used when `stages` is all twos.
+fn digit_reverse<T>(data: &mut [Complex<T>], stages: &[usize]) where T: Float {
+    let n = data.len();
+    let original = data.to_vec();
+    for i in 0..n {
+        let mut x = i;
+        let mut rev = 0;
+        for &radix in stages {
+            rev = rev * radix + x % radix;
+            x /= radix;
+        }
+        data[i] = original[rev];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {c64, Operation, Plan, Transform, TransformError};
+    use test_util::naive_dft;
+
+    #[test]
+    fn try_transform_rejects_length_mismatch() {
+        let plan = Plan::new(Operation::Forward, 4);
+        let mut data = vec![c64::new(0.0, 0.0); 5];
+        assert_eq!(Transform::try_transform(&mut data[..], &plan), Err(TransformError::LengthMismatch));
+    }
+
+    #[test]
+    fn try_transform_rejects_czt_only_plan() {
+        let w = c64::new(1.0, 0.0);
+        let a = c64::new(1.0, 0.0);
+        let plan = Plan::czt(4, 4, w, a);
+        let mut data = vec![c64::new(0.0, 0.0); 4];
+        assert_eq!(Transform::try_transform(&mut data[..], &plan), Err(TransformError::CztOnly));
+    }
+
+    #[test]
+    fn matches_naive_dft_for_composite_length() {
+        // 15 = 3 * 5, a mixed-radix length with no power-of-two factor.
+        let n = 15;
+        let input: Vec<c64> = (0..n).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut data = input.clone();
+
+        Transform::transform(&mut data[..], &Plan::new(Operation::Forward, n));
+
+        let expected = naive_dft(&input, -1.0);
+        for (a, b) in data.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn round_trips_for_composite_length() {
+        let n = 15;
+        let original: Vec<c64> = (0..n).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut data = original.clone();
+
+        Transform::transform(&mut data[..], &Plan::new(Operation::Forward, n));
+        Transform::transform(&mut data[..], &Plan::new(Operation::Inverse, n));
+
+        for (a, b) in data.iter().zip(original.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+}
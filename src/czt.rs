@@ -0,0 +1,126 @@
+//! Chirp Z-transform: evaluation of the transform at `M` points along an
+//! arbitrary spiral contour `a * w^-k` in the z-plane, rather than only at
+//! the `N` roots of unity.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use complex::mixed_radix;
+use {Operation, Plan};
+
+/// Precomputed state for evaluating a chirp z-transform via Bluestein's
+/// convolution, following Rabiner and Schafer.
+#[derive(Clone, Debug)]
+pub(crate) struct Czt<T> {
+    n: usize,
+    m: usize,
+    l: usize,
+    stages: Vec<usize>,
+    forward: Vec<Complex<T>>,
+    backward: Vec<Complex<T>>,
+    input_chirp: Vec<Complex<T>>,
+    output_chirp: Vec<Complex<T>>,
+    kernel: Vec<Complex<T>>,
+}
+
+impl<T> Czt<T> where T: Float {
+    pub fn new(n: usize, m: usize, w: Complex<T>, a: Complex<T>) -> Self {
+        let zero = Complex::new(T::zero(), T::zero());
+        let one = Complex::new(T::one(), T::zero());
+        let two = T::one() + T::one();
+
+        let max_nm = if n > m { n } else { m };
+        let mut w_half_power = Vec::with_capacity(max_nm);
+        for k in 0..max_nm {
+            let exponent = T::from(k * k).unwrap() / two;
+            w_half_power.push(power(w, exponent));
+        }
+
+        let a_inverse = one / a;
+        let mut a_power = one;
+        let mut input_chirp = Vec::with_capacity(n);
+        for k in 0..n {
+            input_chirp.push(a_power * w_half_power[k]);
+            a_power = a_power * a_inverse;
+        }
+
+        let l = (n + m - 1).next_power_of_two();
+        let mut kernel = vec![zero; l];
+        for k in 0..m {
+            kernel[k] = w_half_power[k].inv();
+        }
+        for k in 1..n {
+            kernel[l - k] = w_half_power[k].inv();
+        }
+
+        let forward_plan = Plan::new(Operation::Forward, l);
+        let backward_plan = Plan::new(Operation::Backward, l);
+        mixed_radix(&mut kernel, &forward_plan.stages, &forward_plan.factors, Operation::Forward);
+
+        Czt {
+            n: n,
+            m: m,
+            l: l,
+            stages: forward_plan.stages,
+            forward: forward_plan.factors,
+            backward: backward_plan.factors,
+            input_chirp: input_chirp,
+            output_chirp: w_half_power,
+            kernel: kernel,
+        }
+    }
+
+    /// Evaluate the chirp z-transform of `data`, whose length must equal the
+    /// `n` the plan was created with, producing `m` output samples.
+    pub fn transform(&self, data: &[Complex<T>]) -> Vec<Complex<T>> {
+        let zero = Complex::new(T::zero(), T::zero());
+
+        let mut buffer = vec![zero; self.l];
+        for k in 0..self.n {
+            buffer[k] = data[k] * self.input_chirp[k];
+        }
+
+        mixed_radix(&mut buffer, &self.stages, &self.forward, Operation::Forward);
+        for k in 0..self.l {
+            buffer[k] = buffer[k] * self.kernel[k];
+        }
+        mixed_radix(&mut buffer, &self.stages, &self.backward, Operation::Inverse);
+
+        (0..self.m).map(|k| buffer[k] * self.output_chirp[k]).collect()
+    }
+}
+
+/// Raise `base` to an arbitrary real power via the principal complex
+/// logarithm, since `w` and `a` need not lie on the unit circle.
+fn power<T>(base: Complex<T>, exponent: T) -> Complex<T> where T: Float {
+    (base.ln() * Complex::new(exponent, T::zero())).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use c64;
+    use {chirp_transform, Operation, Plan, Transform};
+
+    #[test]
+    fn matches_forward_dft_on_unit_circle() {
+        // With `a = 1`, the chirp z-transform evaluates `X[k] = sum_j x[j] *
+        // w^(j * k)`; taking `w = exp(-2*pi*i / n)` makes that exactly the
+        // regular forward DFT.
+        let n = 7;
+        let pi = ::std::f64::consts::PI;
+        let theta = 2.0 * pi / n as f64;
+        let w = c64::new(theta.cos(), -theta.sin());
+        let a = c64::new(1.0, 0.0);
+
+        let input: Vec<c64> = (0..n).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut expected = input.clone();
+        Transform::transform(&mut expected[..], &Plan::new(Operation::Forward, n));
+
+        let plan = Plan::czt(n, n, w, a);
+        let actual = chirp_transform(&input, &plan);
+
+        for (a, b) in actual.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Errors returned by the fallible planning and transform functions.
+
+use std::error;
+use std::fmt;
+
+/// An error that can occur while creating a plan.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlanError {
+    /// The number of points was zero.
+    ZeroLength,
+    /// The number of points cannot be planned directly; see `Plan::new_any`.
+    NotSupportedLength,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            PlanError::ZeroLength => "the number of points is zero",
+            PlanError::NotSupportedLength => {
+                "the number of points does not factor into 2, 3, 4, 5, or 7"
+            },
+        };
+        formatter.write_str(message)
+    }
+}
+
+impl error::Error for PlanError {
+    fn description(&self) -> &str {
+        match *self {
+            PlanError::ZeroLength => "the number of points is zero",
+            PlanError::NotSupportedLength => "the number of points is not supported",
+        }
+    }
+}
+
+/// An error that can occur while performing a transform.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransformError {
+    /// The buffer length did not match the number of points the plan was
+    /// created for.
+    LengthMismatch,
+    /// The real transform was asked for an odd number of points, which its
+    /// packed positive-frequency format cannot represent.
+    OddLength,
+    /// The plan was created with `Plan::czt`, which is only usable with
+    /// `chirp_transform`.
+    CztOnly,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            TransformError::LengthMismatch => "the buffer length does not match the plan",
+            TransformError::OddLength => "the real transform requires an even number of points",
+            TransformError::CztOnly => "the plan was created with Plan::czt; use chirp_transform",
+        };
+        formatter.write_str(message)
+    }
+}
+
+impl error::Error for TransformError {
+    fn description(&self) -> &str {
+        match *self {
+            TransformError::LengthMismatch => "the buffer length does not match the plan",
+            TransformError::OddLength => "the real transform requires an even number of points",
+            TransformError::CztOnly => "the plan was created with Plan::czt; use chirp_transform",
+        }
+    }
+}
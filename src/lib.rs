@@ -22,7 +22,7 @@
 //!
 //! let plan = Plan::new(Operation::Forward, 512);
 //! let mut data = vec![c64::new(42.0, 69.0); 512];
-//! dft::transform(&mut data, &plan);
+//! dft::transform(&mut data[..], &plan);
 //! ```
 //!
 //! ## References
@@ -49,9 +49,20 @@ pub type c64 = Complex<f64>;
 
 macro_rules! c(($re:expr, $im:expr) => (::num_complex::Complex::new($re, $im)));
 
+mod bluestein;
 mod complex;
+mod czt;
+mod error;
+mod multi;
 mod real;
+#[cfg(test)]
+mod test_util;
 
+use bluestein::Bluestein;
+use czt::Czt;
+
+pub use error::{PlanError, TransformError};
+pub use multi::{PlanMulti, transform_nd};
 pub use real::unpack;
 
 /// A transform operation.
@@ -69,45 +80,165 @@ pub enum Operation {
 #[derive(Clone, Debug)]
 pub struct Plan<T> {
     n: usize,
+    stages: Vec<usize>,
     factors: Vec<Complex<T>>,
     operation: Operation,
+    bluestein: Option<Box<Bluestein<T>>>,
+    czt: Option<Box<Czt<T>>>,
+}
+
+/// The radices, most preferred first, that `Plan::new` factors a length
+/// into. Lengths with a prime factor outside this set are not directly
+/// supported by `Plan::new`; see `Plan::new_any`.
+const RADICES: [usize; 5] = [4, 2, 3, 5, 7];
+
+/// Factor `n` into a sequence of radices drawn from `RADICES`, in the order
+/// they should be combined. Returns `None` if `n` is zero or has a prime
+/// factor that is not among `RADICES`.
+fn factorize(mut n: usize) -> Option<Vec<usize>> {
+    if n == 0 {
+        return None;
+    }
+    let mut stages = vec![];
+    for &radix in &RADICES {
+        while n % radix == 0 {
+            stages.push(radix);
+            n /= radix;
+        }
+    }
+    if n == 1 { Some(stages) } else { None }
 }
 
 /// The transform.
 pub trait Transform<T> {
     /// Perform the transform.
+    ///
+    /// Panics if the buffer's length does not match the plan's. See
+    /// `try_transform` for a non-panicking alternative.
     fn transform(&mut self, &Plan<T>);
+
+    /// Perform the transform, without panicking if the buffer's length does
+    /// not match the plan's.
+    fn try_transform(&mut self, &Plan<T>) -> Result<(), TransformError>;
 }
 
 impl<T> Plan<T> where T: Float {
     /// Create a plan for a specific operation and specific number of points.
     ///
-    /// The number of points should be a power of two.
+    /// The number of points should be a power of two, or, more generally,
+    /// factor entirely into 2s, 3s, 4s, 5s, and 7s; `Plan::new` uses a
+    /// mixed-radix transform that combines stages of those radices.
+    ///
+    /// Panics if the number of points is zero or is not such a length. See
+    /// `try_new` for a non-panicking alternative.
     pub fn new(operation: Operation, n: usize) -> Self {
-        assert!(n.is_power_of_two());
-        let zero = T::zero();
-        let one = T::one();
-        let two = one + one;
-        let pi = T::acos(-one);
+        Plan::try_new(operation, n).unwrap()
+    }
+
+    /// Create a plan for a specific operation and specific number of points,
+    /// without panicking if the number of points is unsupported.
+    ///
+    /// See `Plan::new` for the supported lengths.
+    pub fn try_new(operation: Operation, n: usize) -> Result<Self, PlanError> {
+        if n == 0 {
+            return Err(PlanError::ZeroLength);
+        }
+        let stages = match factorize(n) {
+            Some(stages) => stages,
+            None => return Err(PlanError::NotSupportedLength),
+        };
+        let sign = if let Operation::Forward = operation { -T::one() } else { T::one() };
+        let two = T::one() + T::one();
+        let pi = T::acos(-T::one());
+
         let mut factors = vec![];
-        let sign = if let Operation::Forward = operation { -one } else { one };
-        let mut step = 1;
-        while step < n {
-            let (multiplier, mut factor) = {
-                let theta = pi / T::from(step).unwrap();
-                let sine = (theta / two).sin();
-                (c!(-two * sine * sine, sign * theta.sin()), c!(one, zero))
-            };
-            for _ in 0..step {
-                factors.push(factor);
-                factor = multiplier * factor + factor;
+        let mut size = 1;
+        for &radix in &stages {
+            let step = size * radix;
+            for j in 0..step {
+                let theta = sign * two * pi * T::from(j).unwrap() / T::from(step).unwrap();
+                factors.push(c!(theta.cos(), theta.sin()));
             }
-            step <<= 1;
+            size = step;
+        }
+
+        Ok(Plan { n: n, stages: stages, factors: factors, operation: operation, bluestein: None, czt: None })
+    }
+
+    /// Create a plan for a specific operation and specific number of points,
+    /// without requiring the number of points to factor into 2s, 3s, 4s, 5s,
+    /// and 7s.
+    ///
+    /// Lengths that `Plan::new` cannot factor are handled via Bluestein's
+    /// algorithm, which expresses the transform as a convolution and
+    /// evaluates it using the mixed-radix machinery. Lengths `Plan::new`
+    /// already handles are still planned directly, exactly as in
+    /// `Plan::new`.
+    ///
+    /// Panics if the number of points is zero. See `try_new_any` for a
+    /// non-panicking alternative.
+    pub fn new_any(operation: Operation, n: usize) -> Self {
+        Plan::try_new_any(operation, n).unwrap()
+    }
+
+    /// Create a plan as in `new_any`, without panicking if the number of
+    /// points is zero.
+    pub fn try_new_any(operation: Operation, n: usize) -> Result<Self, PlanError> {
+        if n == 0 {
+            return Err(PlanError::ZeroLength);
+        }
+        if factorize(n).is_some() {
+            return Plan::try_new(operation, n);
         }
-        Plan { n: n, factors: factors, operation: operation }
+        let bluestein = Bluestein::new(operation, n);
+        Ok(Plan {
+            n: n,
+            stages: vec![],
+            factors: vec![],
+            operation: operation,
+            bluestein: Some(Box::new(bluestein)),
+            czt: None,
+        })
+    }
+
+    /// Create a plan for evaluating the chirp z-transform of `n` points at
+    /// `m` points `a * w^-k` for `k = 0, .., m - 1`, spiralling through the
+    /// z-plane rather than sampling only the `n`-th roots of unity.
+    ///
+    /// The resulting plan is only usable with `chirp_transform`, not with
+    /// `Transform::transform`, since the input and output lengths may
+    /// differ.
+    ///
+    /// Panics if `n` or `m` is zero. See `try_czt` for a non-panicking
+    /// alternative.
+    pub fn czt(n: usize, m: usize, w: Complex<T>, a: Complex<T>) -> Self {
+        Plan::try_czt(n, m, w, a).unwrap()
+    }
+
+    /// Create a plan as in `czt`, without panicking if `n` or `m` is zero.
+    pub fn try_czt(n: usize, m: usize, w: Complex<T>, a: Complex<T>) -> Result<Self, PlanError> {
+        if n == 0 || m == 0 {
+            return Err(PlanError::ZeroLength);
+        }
+        let czt = Czt::new(n, m, w, a);
+        Ok(Plan {
+            n: n,
+            stages: vec![],
+            factors: vec![],
+            operation: Operation::Forward,
+            bluestein: None,
+            czt: Some(Box::new(czt)),
+        })
     }
 }
 
+/// Evaluate the chirp z-transform described by `plan`, which must have been
+/// created with `Plan::czt`.
+pub fn chirp_transform<T>(data: &[Complex<T>], plan: &Plan<T>) -> Vec<Complex<T>> where T: Float {
+    let czt = plan.czt.as_ref().expect("the plan was not created with Plan::czt");
+    czt.transform(data)
+}
+
 /// Perform the transform.
 ///
 /// The function is a shortcut for `Transform::transform`.
@@ -115,3 +246,47 @@ impl<T> Plan<T> where T: Float {
 pub fn transform<D: ?Sized, T>(data: &mut D, plan: &Plan<T>) where D: Transform<T> {
     Transform::transform(data, plan);
 }
+
+/// Perform the transform, without panicking if the buffer's length does not
+/// match the plan's.
+///
+/// The function is a shortcut for `Transform::try_transform`.
+#[inline(always)]
+pub fn try_transform<D: ?Sized, T>(data: &mut D, plan: &Plan<T>) -> Result<(), TransformError>
+    where D: Transform<T>
+{
+    Transform::try_transform(data, plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use {c64, Operation, Plan, PlanError};
+
+    #[test]
+    fn try_new_rejects_zero_length() {
+        assert_eq!(Plan::<f64>::try_new(Operation::Forward, 0), Err(PlanError::ZeroLength));
+    }
+
+    #[test]
+    fn try_new_rejects_unsupported_length() {
+        // 11 is prime and not among `RADICES`, so `Plan::new` can't factor it.
+        assert_eq!(Plan::<f64>::try_new(Operation::Forward, 11), Err(PlanError::NotSupportedLength));
+    }
+
+    #[test]
+    fn try_new_any_rejects_zero_length() {
+        assert_eq!(Plan::<f64>::try_new_any(Operation::Forward, 0), Err(PlanError::ZeroLength));
+    }
+
+    #[test]
+    fn try_new_any_accepts_unsupported_length() {
+        assert!(Plan::<f64>::try_new_any(Operation::Forward, 11).is_ok());
+    }
+
+    #[test]
+    fn try_czt_rejects_zero_length() {
+        let w = c64::new(1.0, 0.0);
+        let a = c64::new(1.0, 0.0);
+        assert_eq!(Plan::try_czt(0, 0, w, a), Err(PlanError::ZeroLength));
+    }
+}
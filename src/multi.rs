@@ -0,0 +1,101 @@
+//! Multi-dimensional transforms over row-major buffers.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use complex::mixed_radix_pass;
+use {Operation, Plan};
+
+/// A transform plan for a row-major, multi-dimensional buffer.
+///
+/// `PlanMulti` reuses a one-dimensional `Plan` for each axis and applies the
+/// transform axis by axis; see `transform_nd`.
+#[derive(Clone, Debug)]
+pub struct PlanMulti<T> {
+    dims: Vec<usize>,
+    operation: Operation,
+    axes: Vec<Plan<T>>,
+}
+
+impl<T> PlanMulti<T> where T: Float {
+    /// Create a plan for a specific operation and specific per-axis lengths.
+    ///
+    /// Each axis length is planned independently via `Plan::new`, so every
+    /// axis must factor into 2s, 3s, 4s, 5s, or 7s.
+    pub fn new(operation: Operation, dims: &[usize]) -> Self {
+        let axes = dims.iter().map(|&n| Plan::new(operation, n)).collect();
+        PlanMulti { dims: dims.to_vec(), operation: operation, axes: axes }
+    }
+}
+
+/// Perform a separable multi-dimensional transform of `data`, a row-major
+/// buffer of the lengths in `dims`, in place.
+///
+/// Row-major means the last dimension is contiguous: the element at indices
+/// `(i_0, .., i_{k-1})` lives at `data[i_0 * dims[1] * .. * dims[k-1] + .. +
+/// i_{k-1}]`. The transform is applied one axis at a time, starting with the
+/// last dimension, where it runs over contiguous elements, and proceeding to
+/// each preceding dimension, where it strides over the elements that vary
+/// along it. The `1 / n` normalization for `Operation::Inverse` is applied
+/// once, over the total element count, rather than once per axis.
+pub fn transform_nd<T>(data: &mut [Complex<T>], dims: &[usize], plan: &PlanMulti<T>) where T: Float {
+    debug_assert_eq!(dims, &plan.dims[..]);
+    debug_assert_eq!(data.len(), dims.iter().product::<usize>());
+
+    for axis in (0..dims.len()).rev() {
+        transform_axis(data, dims, axis, &plan.axes[axis]);
+    }
+
+    if let Operation::Inverse = plan.operation {
+        let one_over_total = T::one() / T::from(data.len()).unwrap();
+        for x in data.iter_mut() {
+            *x = *x * one_over_total;
+        }
+    }
+}
+
+/// Apply `plan` to every line of `data` running along `axis`.
+fn transform_axis<T>(data: &mut [Complex<T>], dims: &[usize], axis: usize, plan: &Plan<T>)
+    where T: Float
+{
+    let len = dims[axis];
+    let stride: usize = dims[axis + 1..].iter().product();
+    let block = stride * len;
+    let zero = Complex::new(T::zero(), T::zero());
+
+    let mut line = vec![zero; len];
+    let mut start = 0;
+    while start < data.len() {
+        for offset in 0..stride {
+            for i in 0..len {
+                line[i] = data[start + offset + i * stride];
+            }
+            mixed_radix_pass(&mut line, &plan.stages, &plan.factors);
+            for i in 0..len {
+                data[start + offset + i * stride] = line[i];
+            }
+        }
+        start += block;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use c64;
+    use {Operation, PlanMulti};
+    use super::transform_nd;
+
+    #[test]
+    fn round_trips_for_2d_buffer() {
+        let dims = [2, 3];
+        let original: Vec<c64> = (0..6).map(|i| c64::new((i + 1) as f64, 0.0)).collect();
+        let mut data = original.clone();
+
+        transform_nd(&mut data, &dims, &PlanMulti::new(Operation::Forward, &dims));
+        transform_nd(&mut data, &dims, &PlanMulti::new(Operation::Inverse, &dims));
+
+        for (a, b) in data.iter().zip(original.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Real-to-complex transform.
+//!
+//! The packed positive-frequency format stores the real DC and Nyquist bins
+//! together in `self[0]`/`self[1]`, so it only supports an even number of
+//! points.
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use complex::mixed_radix;
+use {Operation, Plan, Transform, TransformError};
+
+impl<T> Transform<T> for [T] where T: Float {
+    /// Perform the real transform.
+    ///
+    /// The packed positive-frequency format stores the real Nyquist bin
+    /// alongside the real DC bin, which only exists for an even number of
+    /// points; panics if `plan` was created for an odd number of points.
+    /// Also panics if `plan` was created with `Plan::czt`, which is only
+    /// usable with `chirp_transform`.
+    fn transform(&mut self, plan: &Plan<T>) {
+        let n = plan.n;
+        assert_eq!(self.len(), n);
+        assert!(n % 2 == 0, "the real transform requires an even number of points");
+        assert!(plan.czt.is_none(), "the plan was created with Plan::czt; use chirp_transform");
+        let zero = T::zero();
+
+        match plan.operation {
+            Operation::Forward => {
+                let mut buffer: Vec<_> = self.iter().map(|&x| Complex::new(x, zero)).collect();
+                forward(&mut buffer, plan);
+                self[0] = buffer[0].re;
+                self[1] = buffer[n / 2].re;
+                for i in 1..n / 2 {
+                    self[2 * i] = buffer[i].re;
+                    self[2 * i + 1] = buffer[i].im;
+                }
+            },
+            Operation::Backward | Operation::Inverse => {
+                let mut buffer = vec![Complex::new(zero, zero); n];
+                buffer[0] = Complex::new(self[0], zero);
+                buffer[n / 2] = Complex::new(self[1], zero);
+                for i in 1..n / 2 {
+                    buffer[i] = Complex::new(self[2 * i], self[2 * i + 1]);
+                    buffer[n - i] = buffer[i].conj();
+                }
+                backward(&mut buffer, plan);
+                for (x, c) in self.iter_mut().zip(buffer.iter()) {
+                    *x = c.re;
+                }
+            },
+        }
+    }
+
+    fn try_transform(&mut self, plan: &Plan<T>) -> Result<(), TransformError> {
+        if self.len() != plan.n {
+            return Err(TransformError::LengthMismatch);
+        }
+        if plan.n % 2 != 0 {
+            return Err(TransformError::OddLength);
+        }
+        if plan.czt.is_some() {
+            return Err(TransformError::CztOnly);
+        }
+        self.transform(plan);
+        Ok(())
+    }
+}
+
+fn forward<T>(buffer: &mut [Complex<T>], plan: &Plan<T>) where T: Float {
+    if let Some(ref bluestein) = plan.bluestein {
+        bluestein.transform(buffer, Operation::Forward);
+    } else {
+        mixed_radix(buffer, &plan.stages, &plan.factors, Operation::Forward);
+    }
+}
+
+fn backward<T>(buffer: &mut [Complex<T>], plan: &Plan<T>) where T: Float {
+    if let Some(ref bluestein) = plan.bluestein {
+        bluestein.transform(buffer, plan.operation);
+    } else {
+        mixed_radix(buffer, &plan.stages, &plan.factors, plan.operation);
+    }
+}
+
+/// Unpack the positive-frequency half of a real transform into full complex
+/// form.
+pub fn unpack<T>(data: &[T]) -> Vec<Complex<T>> where T: Float {
+    let n = data.len();
+    let zero = T::zero();
+    let mut result = Vec::with_capacity(n / 2 + 1);
+    result.push(Complex::new(data[0], zero));
+    for i in 1..n / 2 {
+        result.push(Complex::new(data[2 * i], data[2 * i + 1]));
+    }
+    result.push(Complex::new(data[1], zero));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use {Operation, Plan, Transform, TransformError};
+
+    #[test]
+    fn try_transform_rejects_length_mismatch() {
+        let plan = Plan::new(Operation::Forward, 4);
+        let mut data = vec![0.0f64; 5];
+        assert_eq!(Transform::try_transform(&mut data[..], &plan), Err(TransformError::LengthMismatch));
+    }
+
+    #[test]
+    fn try_transform_rejects_odd_length() {
+        let plan = Plan::new_any(Operation::Forward, 9);
+        let mut data = vec![0.0f64; 9];
+        assert_eq!(Transform::try_transform(&mut data[..], &plan), Err(TransformError::OddLength));
+    }
+}
@@ -0,0 +1,19 @@
+//! Shared fixtures for the test modules scattered across the crate.
+
+use c64;
+
+/// Evaluate the naive O(n^2) DFT of `input`, for comparison against the
+/// transforms under test. `sign` is `-1.0` for the forward transform and
+/// `1.0` for the backward transform.
+pub fn naive_dft(input: &[c64], sign: f64) -> Vec<c64> {
+    let n = input.len();
+    let pi = ::std::f64::consts::PI;
+    (0..n).map(|k| {
+        let mut sum = c64::new(0.0, 0.0);
+        for (j, &x) in input.iter().enumerate() {
+            let theta = sign * 2.0 * pi * (j * k) as f64 / n as f64;
+            sum = sum + x * c64::new(theta.cos(), theta.sin());
+        }
+        sum
+    }).collect()
+}